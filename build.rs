@@ -13,6 +13,8 @@ fn main() {
     // Copy icons for runtime use
     copy_icon("tray_dark.ico", target_dir);
     copy_icon("tray_light.ico", target_dir);
+    copy_icon("tray_dark_active.ico", target_dir);
+    copy_icon("tray_light_active.ico", target_dir);
 
     println!("Icons copied to {:?}", target_dir);
 